@@ -51,6 +51,18 @@ class SpectraEngine:
     """
 
     _NAMESPACE = uuid.UUID("3b1c7a74-0f9a-4c61-9cc8-8f2a3f5e0f5f")
+    _DEFAULT_MEMORY_LIMIT = "2GB"
+    _DEFAULT_THREADS = 2
+
+    # Columns the runtime's SQL assumes (nlquery's documented schema, constraint engine).
+    # Shards from older Forge builds can lack them and would otherwise fail at query time.
+    _REQUIRED_COLUMNS: Dict[str, Tuple[str, ...]] = {
+        "claims": ("claim_id", "subject", "predicate", "object", "object_type", "tier"),
+        "entities": ("entity_id", "label"),
+    }
+
+    # Bare cross-shard view names created by _rebuild_union_views.
+    _UNION_VIEW_NAMES = ("claims", "entities", "provenance", "spans", "temporal", "lineage", "refs")
 
     def __init__(
         self,
@@ -59,10 +71,31 @@ class SpectraEngine:
         cache_path: Optional[str] = None,
         db_path: Optional[str] = None,
         temp_root: Optional[str] = None,
+        memory_limit: Optional[str] = None,
+        threads: Optional[int] = None,
     ) -> None:
         self._start_time = time.time()
         self._lock = threading.RLock()
         self.con = duckdb.connect(":memory:")
+
+        # DuckDB defaults to most of the machine's RAM and every core. Keep the
+        # runtime a polite neighbour unless the deployer asks for more.
+        # server.py builds the engine at import time, so name the variable on bad env values.
+        if threads is None:
+            raw_threads = os.environ.get("SPECTRA_DUCKDB_THREADS", str(self._DEFAULT_THREADS))
+            try:
+                threads = int(raw_threads)
+            except ValueError:
+                raise ValueError(f"SPECTRA_DUCKDB_THREADS must be an integer, got {raw_threads!r}") from None
+        memory_from_env = memory_limit is None
+        if memory_from_env:
+            memory_limit = os.environ.get("SPECTRA_DUCKDB_MEMORY_LIMIT", self._DEFAULT_MEMORY_LIMIT)
+        try:
+            self.set_resource_limits(memory_limit=memory_limit, threads=threads)
+        except ValueError as e:
+            if memory_from_env and str(e).startswith("invalid memory_limit"):
+                raise ValueError(f"SPECTRA_DUCKDB_MEMORY_LIMIT: {e}") from None
+            raise
         self._mount_dirs: Dict[str, Path] = {}
         self._mount_specs: Dict[str, MountSpec] = {}
         self._claims: Dict[str, List[Dict[str, Any]]] = {}
@@ -192,15 +225,13 @@ class SpectraEngine:
                 raise ValueError(f"PROVENANCE_OUT_OF_BOUNDS: source file missing for hash {h}: {fp}")
             hash_to_size[h] = fp.stat().st_size
 
-        # Read spans using DuckDB (no pyarrow dependency).
-        con = duckdb.connect(":memory:")
-        try:
-            rows = con.execute(
+        # Read spans on the engine connection so the configured memory/thread limits apply.
+        with self._lock:
+            self._check_parquet_readable(self.con, spans_path, "evidence/spans.parquet")
+            rows = self.con.execute(
                 "SELECT source_hash, byte_start, byte_end FROM read_parquet(?)",
                 [str(spans_path)],
             ).fetchall()
-        finally:
-            con.close()
 
         for source_hash, byte_start, byte_end in rows:
             if not isinstance(source_hash, str) or source_hash not in hash_to_size:
@@ -227,6 +258,20 @@ class SpectraEngine:
                     f"{bs}..{be} (size {size})"
                 )
 
+    @staticmethod
+    def _check_parquet_readable(con: duckdb.DuckDBPyConnection, pq_path: Path, rel_path: str) -> None:
+        """Reject zero-byte or unreadable Parquet with a message naming the file.
+
+        A Forge build that crashed mid-write leaves files that pass exists() but
+        fail later with a raw DuckDB error.
+        """
+        if pq_path.stat().st_size == 0:
+            raise ValueError(f"Genesis shard file is empty or corrupt: {rel_path}")
+        try:
+            con.execute("SELECT * FROM read_parquet(?) LIMIT 0", [str(pq_path)]).fetchall()
+        except duckdb.Error as e:
+            raise ValueError(f"Genesis shard file is empty or corrupt: {rel_path} ({e})") from e
+
     def boot(self) -> Dict[str, Any]:
         """Rehydrate state from the System Catalog."""
         self.catalog.log_system_event("boot_start")
@@ -285,6 +330,36 @@ class SpectraEngine:
             "index_size": self.index_size(),
         }
 
+    def set_resource_limits(self, *, memory_limit: Optional[str] = None, threads: Optional[int] = None) -> Dict[str, Any]:
+        """Apply DuckDB memory_limit / threads to the query connection.
+
+        Either value may be omitted to leave the current setting unchanged.
+        """
+        # Validate everything before the first SET so a rejected call changes nothing.
+        n: Optional[int] = None
+        if threads is not None:
+            n = int(threads)
+            if n < 1:
+                raise ValueError(f"threads must be >= 1, got {n}")
+
+        with self._lock:
+            if memory_limit is not None:
+                lit = str(memory_limit).replace("'", "''")
+                try:
+                    self.con.execute(f"SET memory_limit = '{lit}'")
+                except duckdb.Error as e:
+                    raise ValueError(f"invalid memory_limit {memory_limit!r}: {e}") from None
+            if n is not None:
+                self.con.execute(f"SET threads = {n}")
+        return self.resource_limits()
+
+    def resource_limits(self) -> Dict[str, Any]:
+        with self._lock:
+            mem, thr = self.con.execute(
+                "SELECT current_setting('memory_limit'), current_setting('threads')"
+            ).fetchone()
+        return {"memory_limit": mem, "threads": int(thr)}
+
     def index_size(self) -> int:
         with self._lock:
             return self._index.size()
@@ -372,7 +447,18 @@ class SpectraEngine:
                         if required:
                             raise ValueError(f"Genesis shard missing required file: {rel_path}")
                         continue
+                    self._check_parquet_readable(self.con, pq_path, rel_path)
                     p = pq_path.as_posix().replace("'", "''")
+
+                    required_cols = self._REQUIRED_COLUMNS.get(table_name)
+                    if required_cols:
+                        present = {
+                            r[0] for r in self.con.execute(f"DESCRIBE SELECT * FROM read_parquet('{p}')").fetchall()
+                        }
+                        missing = [c for c in required_cols if c not in present]
+                        if missing:
+                            raise ValueError(f"SCHEMA_MISMATCH: {rel_path} missing column(s): {', '.join(missing)}")
+
                     view_name = f"{table_name}__{mount_prefix}__{sanitize_identifier(shard_id)}"
                     self.con.execute(
                         f"CREATE OR REPLACE VIEW {quote_ident(view_name)} AS SELECT * FROM read_parquet('{p}')"
@@ -384,6 +470,7 @@ class SpectraEngine:
                 if ext_dir.is_dir():
                     for ext_file in sorted(ext_dir.iterdir()):
                         if ext_file.suffix == ".parquet" and ext_file.is_file():
+                            self._check_parquet_readable(self.con, ext_file, f"ext/{ext_file.name}")
                             p = ext_file.as_posix().replace("'", "''")
                             view_name = f"ext_{ext_file.stem}__{mount_prefix}__{sanitize_identifier(shard_id)}"
                             self.con.execute(
@@ -557,8 +644,11 @@ class SpectraEngine:
 
     def query_json(self, sql: str, *, token_hash: Optional[str] = None) -> Dict[str, Any]:
         start = time.perf_counter()
-        if not is_read_only_sql(sql):
-            raise ValueError("Query rejected. Read-only SQL only.")
+        with self._lock:
+            # Per-shard views plus the bare union names from _rebuild_union_views.
+            allowed = {v for s in self._mount_specs.values() for v in s.tables} | set(self._UNION_VIEW_NAMES)
+        if not is_read_only_sql(sql, allowed_tables=allowed):
+            raise ValueError("Query rejected. Read-only SQL over mounted views only.")
 
         with self._lock:
             res = self.con.execute(sql)
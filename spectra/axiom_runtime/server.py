@@ -40,6 +40,11 @@ class ChatRequest(BaseModel):
     top_k: int = 7
 
 
+class LimitsRequest(BaseModel):
+    memory_limit: Optional[str] = None
+    threads: Optional[int] = None
+
+
 def require_token(x_spectra_token: Optional[str] = Header(default=None)) -> None:
     if not _API_TOKEN:
         return
@@ -92,6 +97,19 @@ def get_catalog(_auth: None = Depends(require_token)) -> Dict[str, Any]:
     return engine.catalog_json()
 
 
+@app.get("/limits")
+def get_limits(_auth: None = Depends(require_token)) -> Dict[str, Any]:
+    return engine.resource_limits()
+
+
+@app.post("/limits")
+def set_limits(req: LimitsRequest, _auth: None = Depends(require_token)) -> Dict[str, Any]:
+    try:
+        return engine.set_resource_limits(memory_limit=req.memory_limit, threads=req.threads)
+    except Exception as e:
+        raise HTTPException(status_code=400, detail=str(e))
+
+
 @app.post("/query")
 def query_sql(
     req: Dict[str, str],
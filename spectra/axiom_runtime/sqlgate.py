@@ -1,8 +1,71 @@
-import re
+import json
+from typing import Any, Iterable, Iterator, Set
 
-_READONLY_RE = re.compile(r"^\s*(select|with)\b", re.IGNORECASE)
+import duckdb
 
-def is_read_only_sql(sql: str) -> bool:
+
+def _walk(node: Any) -> Iterator[dict]:
+    if isinstance(node, dict):
+        yield node
+        for v in node.values():
+            yield from _walk(v)
+    elif isinstance(node, list):
+        for v in node:
+            yield from _walk(v)
+
+
+def _cte_names(nodes: Iterable[dict]) -> Set[str]:
+    names: Set[str] = set()
+    for node in nodes:
+        cte_map = node.get("cte_map")
+        if isinstance(cte_map, dict):
+            for entry in cte_map.get("map") or []:
+                if isinstance(entry, dict) and isinstance(entry.get("key"), str):
+                    names.add(entry["key"].lower())
+    return names
+
+
+def _only_reads_tables(sql: str, allowed: Set[str]) -> bool:
+    con = duckdb.connect(":memory:")
+    try:
+        tree = json.loads(con.execute("SELECT json_serialize_sql(?)", [sql]).fetchone()[0])
+    finally:
+        con.close()
+    if tree.get("error"):
+        return False
+
+    nodes = list(_walk(tree.get("statements")))
+    allowed = allowed | _cte_names(nodes)
+    for node in nodes:
+        ref_type = node.get("type")
+        # Every table function is out: read_*, glob, and also query() /
+        # json_execute_serialized_sql(), which run a nested SQL string this gate never sees.
+        if ref_type == "TABLE_FUNCTION":
+            return False
+        if ref_type == "BASE_TABLE":
+            if (node.get("catalog_name") or "") not in ("", "memory"):
+                return False
+            if (node.get("schema_name") or "") not in ("", "main"):
+                return False
+            if str(node.get("table_name") or "").lower() not in allowed:
+                return False
+    return True
+
+
+def is_read_only_sql(sql: str, *, allowed_tables: Iterable[str]) -> bool:
+    """True only for one SELECT (including WITH ... SELECT) over the given tables.
+
+    Parsed by DuckDB itself, so quoting forms the gate does not know about
+    ($$...$$, E'...') cannot hide a second statement. Base tables must be in
+    allowed_tables (or a CTE of the query) and table functions are rejected,
+    so user SQL cannot reach files or other catalogs.
+    """
     if not isinstance(sql, str):
         return False
-    return bool(_READONLY_RE.match(sql))
+    try:
+        statements = duckdb.extract_statements(sql)
+        if len(statements) != 1 or statements[0].type != duckdb.StatementType.SELECT:
+            return False
+        return _only_reads_tables(sql, {t.lower() for t in allowed_tables})
+    except duckdb.Error:
+        return False
@@ -0,0 +1,209 @@
+#!/usr/bin/env python3
+"""Tests for SpectraEngine runtime settings and mount hardening.
+
+Tests:
+1. Constructor memory_limit / threads reach the DuckDB connection
+2. set_resource_limits rejects threads < 1
+3. A rejected set_resource_limits call leaves both settings unchanged
+4. Bad SPECTRA_DUCKDB_THREADS / SPECTRA_DUCKDB_MEMORY_LIMIT fail with a message naming the variable
+5. Zero-byte Parquet is rejected with a message naming the file
+6. A claims table missing a required column fails mount with SCHEMA_MISMATCH
+"""
+
+import json
+import os
+import sys
+import tempfile
+from pathlib import Path
+from typing import Optional
+from unittest import mock
+
+# Add spectra to path
+sys.path.insert(0, str(Path(__file__).parent.parent / "spectra"))
+
+# The system catalog refuses to start without a key outside dev mode.
+os.environ.setdefault("SPECTRA_DEV_MODE", "1")
+
+import duckdb
+
+from axiom_runtime import engine as engine_mod
+from axiom_runtime.engine import SpectraEngine
+
+
+def _make_engine(tmp_dir: str, **kwargs) -> SpectraEngine:
+    tmp = Path(tmp_dir)
+    # retrieval.Embedder and chat.ChatEngine are stubs whose constructors do not
+    # yet take the arguments SpectraEngine passes; these tests don't touch them.
+    with mock.patch.object(engine_mod, "Embedder", mock.MagicMock()), \
+            mock.patch.object(engine_mod, "ChatEngine", mock.MagicMock()):
+        return SpectraEngine(
+            audit_path=str(tmp / "audit.jsonl"),
+            cache_path=str(tmp / "cache.jsonl"),
+            db_path=str(tmp / "spectra.db"),
+            **kwargs,
+        )
+
+
+def test_resource_limits_applied():
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        eng = _make_engine(tmp_dir, memory_limit="256MB", threads=1)
+        limits = eng.resource_limits()
+        assert limits["threads"] == 1
+        # DuckDB reports the limit normalized, e.g. "244.1 MiB" or "256.0 MB".
+        assert limits["memory_limit"].split()[0].startswith(("244", "256")), limits
+
+        limits = eng.set_resource_limits(threads=2)
+        assert limits["threads"] == 2
+
+
+def test_zero_threads_rejected():
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        eng = _make_engine(tmp_dir)
+        try:
+            eng.set_resource_limits(threads=0)
+        except ValueError:
+            pass
+        else:
+            raise AssertionError("threads=0 was accepted")
+
+        try:
+            _make_engine(tmp_dir, threads=0)
+        except ValueError:
+            pass
+        else:
+            raise AssertionError("SpectraEngine(threads=0) was accepted")
+
+
+def test_rejected_update_changes_nothing():
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        eng = _make_engine(tmp_dir, memory_limit="256MB", threads=1)
+        before = eng.resource_limits()
+        try:
+            eng.set_resource_limits(memory_limit="1GB", threads=0)
+        except ValueError:
+            pass
+        else:
+            raise AssertionError("threads=0 was accepted")
+        assert eng.resource_limits() == before
+
+
+def test_bad_env_named():
+    for name, value in [("SPECTRA_DUCKDB_THREADS", "lots"), ("SPECTRA_DUCKDB_MEMORY_LIMIT", "lots")]:
+        with mock.patch.dict(os.environ, {name: value}), tempfile.TemporaryDirectory() as tmp_dir:
+            try:
+                _make_engine(tmp_dir)
+            except ValueError as e:
+                assert name in str(e), e
+            else:
+                raise AssertionError(f"bad {name} was accepted")
+
+
+def _write_parquet(con: duckdb.DuckDBPyConnection, select_sql: str, path: Path) -> None:
+    con.execute(f"COPY ({select_sql}) TO '{path.as_posix()}' (FORMAT parquet)")
+
+
+def _make_shard(root: Path, claims_select: Optional[str] = None) -> Path:
+    """Minimal unsigned shard; claims.parquet is zero bytes unless claims_select is given."""
+    shard = root / "shard"
+    (shard / "sig").mkdir(parents=True)
+    (shard / "content").mkdir()
+    (shard / "evidence").mkdir()
+    (shard / "graph").mkdir()
+
+    (shard / "content" / "source.txt").write_text("hello world", encoding="utf-8")
+    (shard / "manifest.json").write_text(json.dumps({
+        "spec_version": "1.0.0",
+        "shard_id": "fixture",
+        "integrity": {"merkle_root": "0" * 64},
+        "sources": [{"path": "content/source.txt", "hash": "h1"}],
+    }), encoding="utf-8")
+
+    con = duckdb.connect(":memory:")
+    try:
+        _write_parquet(
+            con,
+            "SELECT 'h1' AS source_hash, 0 AS byte_start, 5 AS byte_end",
+            shard / "evidence" / "spans.parquet",
+        )
+        if claims_select is not None:
+            _write_parquet(con, claims_select, shard / "graph" / "claims.parquet")
+    finally:
+        con.close()
+
+    if claims_select is None:
+        # What a Forge build that crashed mid-write leaves behind.
+        (shard / "graph" / "claims.parquet").write_bytes(b"")
+    return shard
+
+
+def _mount_unsigned(eng: SpectraEngine, shard: Path):
+    # The fixture shard is unsigned; skip the constitution gate (tested in genesis)
+    # so mount reaches the Parquet checks under test.
+    with mock.patch.object(SpectraEngine, "_verify_constitution", lambda self, shard_dir: None):
+        return eng.mount_shard(str(shard))
+
+
+def test_zero_byte_parquet_check():
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        empty = Path(tmp_dir) / "claims.parquet"
+        empty.write_bytes(b"")
+        con = duckdb.connect(":memory:")
+        try:
+            SpectraEngine._check_parquet_readable(con, empty, "graph/claims.parquet")
+        except ValueError as e:
+            assert str(e) == "Genesis shard file is empty or corrupt: graph/claims.parquet", e
+        else:
+            raise AssertionError("zero-byte Parquet was accepted")
+        finally:
+            con.close()
+
+
+def test_mount_rejects_zero_byte_claims():
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        shard = _make_shard(Path(tmp_dir))
+        eng = _make_engine(tmp_dir)
+        try:
+            _mount_unsigned(eng, shard)
+        except ValueError as e:
+            assert "Genesis shard file is empty or corrupt: graph/claims.parquet" in str(e), e
+        else:
+            raise AssertionError("shard with zero-byte claims.parquet mounted")
+        assert eng.catalog_json() == {"mounts": []}
+
+
+def test_mount_rejects_missing_claims_column():
+    with tempfile.TemporaryDirectory() as tmp_dir:
+        shard = _make_shard(
+            Path(tmp_dir),
+            claims_select=(
+                "SELECT 'c_1' AS claim_id, 'e_1' AS subject, 'treats' AS predicate, "
+                "'e_2' AS object, 1 AS tier"
+            ),
+        )
+        eng = _make_engine(tmp_dir)
+        try:
+            _mount_unsigned(eng, shard)
+        except ValueError as e:
+            assert str(e) == "SCHEMA_MISMATCH: graph/claims.parquet missing column(s): object_type", e
+        else:
+            raise AssertionError("shard with claims missing object_type mounted")
+        assert eng.catalog_json() == {"mounts": []}
+        assert not [t for t in eng.con.execute("SHOW TABLES").fetchall() if t[0].startswith("claims__")]
+
+
+if __name__ == "__main__":
+    print("=== Spectra Engine Test ===\n")
+
+    for fn in [
+        test_resource_limits_applied,
+        test_zero_threads_rejected,
+        test_rejected_update_changes_nothing,
+        test_bad_env_named,
+        test_zero_byte_parquet_check,
+        test_mount_rejects_zero_byte_claims,
+        test_mount_rejects_missing_claims_column,
+    ]:
+        fn()
+        print(f"✅ {fn.__name__}")
+
+    print("\n✅ SPECTRA ENGINE TEST PASSED")
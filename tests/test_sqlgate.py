@@ -0,0 +1,105 @@
+#!/usr/bin/env python3
+"""Tests for Spectra's read-only SQL gate (INV-18).
+
+Tests:
+1. Plain SELECT / WITH queries over mounted views pass
+2. DML, DDL, ATTACH and COPY ... TO are rejected
+3. Stacked statements and WITH-prefixed DML are rejected
+4. Keywords inside literals and identifiers are not false positives
+5. Table functions (file reads, nested SQL) and unmounted tables are rejected
+"""
+
+import sys
+from pathlib import Path
+
+# Add spectra to path
+sys.path.insert(0, str(Path(__file__).parent.parent / "spectra"))
+
+from axiom_runtime.engine import SpectraEngine
+from axiom_runtime.nlquery import natural_language_to_sql
+from axiom_runtime.sqlgate import is_read_only_sql
+
+# What query_json allows with one shard mounted.
+ALLOWED = set(SpectraEngine._UNION_VIEW_NAMES) | {"claims__0123456789ab__demo", "t"}
+
+
+def _gate(sql: str) -> bool:
+    return is_read_only_sql(sql, allowed_tables=ALLOWED)
+
+
+def test_select_and_with_allowed():
+    assert _gate("SELECT * FROM claims")
+    assert _gate("select 1;")
+    assert _gate("WITH x AS (SELECT 1) SELECT * FROM x")
+    assert _gate('SELECT * FROM "claims__0123456789ab__demo" c JOIN entities e ON c.subject = e.entity_id')
+
+
+def test_writes_rejected():
+    for sql in [
+        "DELETE FROM claims",
+        "DROP VIEW claims",
+        "ATTACH '/tmp/other.db' AS other",
+        "COPY claims TO '/tmp/claims.csv'",
+        "INSERT INTO claims VALUES (1)",
+    ]:
+        assert not _gate(sql), sql
+
+
+def test_stacked_and_cte_writes_rejected():
+    assert not _gate("SELECT 1; DROP VIEW claims")
+    assert not _gate("WITH x AS (SELECT 1) DELETE FROM claims")
+    assert not _gate("SELECT $$'$$; DROP VIEW claims; --'")
+    assert not _gate("SELECT E'\\''; DROP VIEW claims; --'")
+
+
+def test_keywords_in_literals_allowed():
+    assert _gate("SELECT * FROM claims WHERE predicate = 'deleted'")
+    assert _gate("SELECT 'a;b' AS t")
+    assert _gate('SELECT "drop" FROM claims')
+    assert _gate("SELECT load FROM t")
+
+
+def test_file_reads_rejected():
+    for sql in [
+        "SELECT * FROM read_text('/etc/passwd')",
+        "SELECT * FROM read_csv('~/.ssh/id_rsa')",
+        "SELECT * FROM glob('/**')",
+        "SELECT * FROM parquet_scan('/tmp/x.parquet')",
+        "SELECT * FROM '/etc/passwd'",
+        "WITH x AS (SELECT * FROM read_blob('/etc/shadow')) SELECT * FROM x",
+        "SELECT * FROM claims WHERE subject IN (SELECT content FROM read_text('/etc/hosts'))",
+        "SELECT * FROM query('SELECT * FROM read_text(''/etc/passwd'')')",
+        "SELECT * FROM json_execute_serialized_sql(json_serialize_sql('SELECT * FROM read_text(''/etc/passwd'')'))",
+        "SELECT * FROM query_table('claims')",
+        "SELECT * FROM duckdb_settings()",
+    ]:
+        assert not _gate(sql), sql
+
+
+def test_unmounted_tables_rejected():
+    assert not _gate("SELECT * FROM some_other_table")
+    assert not _gate("SELECT * FROM information_schema.tables")
+    assert not _gate("SELECT * FROM other_db.main.claims")
+
+
+def test_nlquery_output_passes_gate():
+    for q in ["what decisions conflict", "show me drop tables", "update delete create notes"]:
+        assert _gate(natural_language_to_sql(q)), q
+
+
+if __name__ == "__main__":
+    print("=== SQL Gate Test ===\n")
+
+    for fn in [
+        test_select_and_with_allowed,
+        test_writes_rejected,
+        test_stacked_and_cte_writes_rejected,
+        test_keywords_in_literals_allowed,
+        test_file_reads_rejected,
+        test_unmounted_tables_rejected,
+        test_nlquery_output_passes_gate,
+    ]:
+        fn()
+        print(f"✅ {fn.__name__}")
+
+    print("\n✅ SQL GATE TEST PASSED")